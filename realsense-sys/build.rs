@@ -20,8 +20,21 @@ fn main() -> Result<()> {
 
     env::set_var("VCPKGRS_DYNAMIC", "1");
 
-    // Probe libary
-    let library = probe_library("realsense2")?;
+    // Probe libary, falling back to a source build when none is installed and the
+    // `buildtime-source` feature opted into it.
+    //
+    // Turning this feature on requires Cargo.toml to declare:
+    //   [features]
+    //   buildtime-source = ["cmake"]
+    //   [build-dependencies]
+    //   cmake = { version = "0.1", optional = true }
+    let library = match probe_library("realsense2") {
+        Ok(library) => library,
+        #[cfg(feature = "buildtime-source")]
+        Err(_) => build_library_from_source("realsense2")?,
+        #[cfg(not(feature = "buildtime-source"))]
+        Err(err) => return Err(err),
+    };
 
     // Verify version
     let (mut include_dir, version) = library
@@ -50,6 +63,8 @@ fn main() -> Result<()> {
         version.to_string()
     );
 
+    emit_version_cfg(&version);
+
     // generate bindings
     #[cfg(feature = "buildtime-bindgen")]
     {
@@ -114,6 +129,23 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Emits `cargo:rustc-cfg` lines describing the detected `librealsense2` version, so the
+/// rest of the crate can `#[cfg(...)]`-gate bindings that only exist on newer SDK
+/// releases instead of failing to compile (or silently linking missing symbols) against
+/// an older installed library.
+fn emit_version_cfg(version: &Version) {
+    println!("cargo:rustc-cfg=librealsense2");
+
+    if let Ok(minor) = version.minor.parse::<u32>() {
+        println!("cargo:rustc-cfg=librealsense2_minor_{}", minor);
+        for at_least in 0..=minor {
+            println!("cargo:rustc-cfg=librealsense2_at_least_2_{}", at_least);
+        }
+    }
+
+    println!("cargo:rustc-env=REALSENSE2_VERSION={}", version.to_string());
+}
+
 fn get_version_from_header_dir<P>(dir: P) -> Option<Version>
 where
     P: AsRef<Path>,
@@ -197,6 +229,107 @@ fn probe_library(pkg_name: &str) -> Result<Library> {
     Ok(lib)
 }
 
+/// The librealsense2 release this crate's source build pins. Bump together with any
+/// bindings regenerated against it.
+#[cfg(feature = "buildtime-source")]
+const PINNED_SOURCE_TAG: &str = "v2.54.2";
+
+#[cfg(feature = "buildtime-source")]
+const PINNED_SOURCE_URL: &str = "https://github.com/IntelRealSense/librealsense.git";
+
+/// Builds a [Library] when no preinstalled `realsense2` package could be found.
+///
+/// `REALSENSE2_LIB_DIR`/`REALSENSE2_INCLUDE_DIR` are honored first, so users on exotic
+/// or cross-compiled targets can point straight at a manually built SDK. Otherwise the
+/// pinned source tree named by [PINNED_SOURCE_TAG] is fetched (or read from
+/// `REALSENSE2_SRC` if set) and built through the `cmake` crate.
+#[cfg(feature = "buildtime-source")]
+fn build_library_from_source(pkg_name: &str) -> Result<Library> {
+    println!("cargo:rerun-if-env-changed=REALSENSE2_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=REALSENSE2_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=REALSENSE2_SRC");
+
+    if let (Ok(lib_dir), Ok(include_dir)) = (
+        env::var("REALSENSE2_LIB_DIR"),
+        env::var("REALSENSE2_INCLUDE_DIR"),
+    ) {
+        let lib_dir = PathBuf::from(lib_dir);
+        let include_dir = PathBuf::from(include_dir);
+        let version = get_version_from_header_dir(include_dir.join("librealsense2"))
+            .expect("fail to detect librealsense2 version under REALSENSE2_INCLUDE_DIR");
+
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+        return Ok(Library {
+            pkg_name: pkg_name.to_owned(),
+            libs: vec![pkg_name.to_owned()],
+            link_paths: vec![lib_dir.clone()],
+            framework_paths: Vec::new(),
+            include_paths: vec![include_dir],
+            version: version.to_string(),
+            prefix: PathBuf::new(),
+            libdir: lib_dir,
+        });
+    }
+
+    let src_dir = match env::var("REALSENSE2_SRC") {
+        Ok(dir) => PathBuf::from(dir),
+        Err(_) => fetch_pinned_source()?,
+    };
+
+    let dst = cmake::Config::new(&src_dir)
+        .define("BUILD_EXAMPLES", "OFF")
+        .define("BUILD_GRAPHICAL_EXAMPLES", "OFF")
+        .define("BUILD_WITH_STATIC_CRT", "OFF")
+        .build();
+
+    let include_dir = dst.join("include");
+    let libdir = dst.join("lib");
+
+    println!("cargo:rustc-link-search=native={}", libdir.display());
+
+    let version = get_version_from_header_dir(include_dir.join("librealsense2"))
+        .expect("fail to detect librealsense2 version in built source tree");
+
+    Ok(Library {
+        pkg_name: pkg_name.to_owned(),
+        libs: vec![pkg_name.to_owned()],
+        link_paths: vec![libdir.clone()],
+        framework_paths: Vec::new(),
+        include_paths: vec![include_dir],
+        version: version.to_string(),
+        prefix: dst,
+        libdir,
+    })
+}
+
+/// Clones [PINNED_SOURCE_TAG] into `OUT_DIR`, reusing a prior checkout if present.
+#[cfg(feature = "buildtime-source")]
+fn fetch_pinned_source() -> Result<PathBuf> {
+    let dest = PathBuf::from(env::var("OUT_DIR")?).join("librealsense2-src");
+
+    if !dest.join("CMakeLists.txt").is_file() {
+        let status = std::process::Command::new("git")
+            .args(&[
+                "clone",
+                "--depth",
+                "1",
+                "--branch",
+                PINNED_SOURCE_TAG,
+                PINNED_SOURCE_URL,
+            ])
+            .arg(&dest)
+            .status()?;
+        anyhow::ensure!(
+            status.success(),
+            "failed to fetch librealsense2 source tree from {}",
+            PINNED_SOURCE_URL
+        );
+    }
+
+    Ok(dest)
+}
+
 #[derive(Debug, Clone)]
 struct Version {
     major: String,
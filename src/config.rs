@@ -4,7 +4,11 @@ use crate::{
     common::*,
     error::{ErrorChecker, Result},
     kind::{Format, StreamKind},
+    pipeline::InactivePipeline,
+    pipeline_profile::PipelineProfile,
 };
+use serde::{Deserialize, Serialize};
+use std::ffi::CString;
 
 /// The pipeline configuration that will be consumed by [Pipeline::start()](crate::pipeline::Pipeline::start).
 #[derive(Debug)]
@@ -100,6 +104,126 @@ impl Config {
     pub(crate) unsafe fn unsafe_clone(&self) -> Self {
         Self { ptr: self.ptr }
     }
+
+    /// Builds a [Config] by replaying the entries of a [ConfigSpec].
+    ///
+    /// This turns a capture setup into portable data: a whole `ConfigSpec` can be
+    /// loaded from a JSON or TOML file at runtime instead of being hard-coded as a
+    /// chain of [enable_stream](Config::enable_stream) calls.
+    pub fn from_spec(spec: &ConfigSpec) -> Result<Self> {
+        let mut config = Self::new()?;
+
+        for stream in &spec.streams {
+            config = config.enable_stream(
+                stream.stream,
+                stream.index,
+                stream.width,
+                stream.height,
+                stream.format,
+                stream.framerate,
+            )?;
+        }
+
+        if let Some(serial) = &spec.device_serial {
+            config = config.enable_device_from_serial(serial)?;
+        }
+
+        if let Some(file) = &spec.playback_file {
+            config = config.enable_device_from_file::<&str>(file)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Resolves the config against a pipeline, without starting it.
+    ///
+    /// This lets callers learn the stream profiles (e.g. resolved width/height/format)
+    /// that a [start](InactivePipeline::start) call with this config would actually
+    /// produce, without the hardware side effect of starting the pipeline.
+    pub fn resolve(&self, pipeline: &InactivePipeline) -> Result<PipelineProfile> {
+        let ptr = unsafe {
+            let mut checker = ErrorChecker::new();
+            let ptr =
+                sys::rs2_config_resolve(self.ptr.as_ptr(), pipeline.ptr(), checker.inner_mut_ptr());
+            checker.check()?;
+            ptr
+        };
+        Ok(unsafe { PipelineProfile::from_raw(ptr) })
+    }
+
+    /// Checks whether this config is satisfiable by the devices a pipeline can see,
+    /// without starting the pipeline.
+    pub fn can_resolve(&self, pipeline: &InactivePipeline) -> bool {
+        unsafe {
+            let mut checker = ErrorChecker::new();
+            let ret = sys::rs2_config_can_resolve(
+                self.ptr.as_ptr(),
+                pipeline.ptr(),
+                checker.inner_mut_ptr(),
+            );
+            checker.check().is_ok() && ret != 0
+        }
+    }
+}
+
+/// One entry of a [ConfigSpec], mirroring an [enable_stream](Config::enable_stream) call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamSpec {
+    pub stream: StreamKind,
+    #[serde(default)]
+    pub index: usize,
+    pub width: usize,
+    pub height: usize,
+    pub format: Format,
+    pub framerate: usize,
+}
+
+/// A serializable description of a [Config], suitable for storing a capture setup as
+/// data (e.g. a JSON or TOML file checked into a project) and loading it at runtime
+/// with [Config::from_spec].
+///
+/// `device_serial`/`playback_file` are stored as [CString] rather than [String] so that
+/// a value which cannot be turned into one — an embedded NUL byte — is rejected the
+/// moment a [ConfigSpec] is built, whether that's by deserializing untrusted JSON/TOML
+/// or by constructing one directly in Rust. [Config::from_spec] therefore never has to
+/// perform (or fail) that conversion itself.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfigSpec {
+    pub streams: Vec<StreamSpec>,
+    #[serde(default, with = "option_cstring")]
+    pub device_serial: Option<CString>,
+    #[serde(default, with = "option_cstring")]
+    pub playback_file: Option<CString>,
+}
+
+/// (De)serializes an `Option<CString>` as plain text, for the `device_serial`/
+/// `playback_file` fields of [ConfigSpec].
+mod option_cstring {
+    use super::CString;
+    use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<CString>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(cstring) => serializer.serialize_some(
+                cstring
+                    .to_str()
+                    .map_err(|_| S::Error::custom("string must be valid UTF-8"))?,
+            ),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<CString>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| CString::new(s).map_err(|_| D::Error::custom("string must not contain a NUL byte")))
+            .transpose()
+    }
 }
 
 impl Drop for Config {
@@ -111,3 +235,41 @@ impl Drop for Config {
 }
 
 unsafe impl Send for Config {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_spec_round_trips_through_json() {
+        let spec = ConfigSpec {
+            streams: vec![StreamSpec {
+                stream: StreamKind::Depth,
+                index: 0,
+                width: 640,
+                height: 480,
+                format: Format::Z16,
+                framerate: 30,
+            }],
+            device_serial: Some(CString::new("0123456789").unwrap()),
+            playback_file: None,
+        };
+
+        let json = serde_json::to_string(&spec).unwrap();
+        let restored: ConfigSpec = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.streams.len(), spec.streams.len());
+        assert_eq!(restored.streams[0].stream, StreamKind::Depth);
+        assert_eq!(restored.streams[0].format, Format::Z16);
+        assert_eq!(restored.streams[0].width, 640);
+        assert_eq!(restored.device_serial, spec.device_serial);
+        assert_eq!(restored.playback_file, spec.playback_file);
+    }
+
+    #[test]
+    fn config_spec_rejects_nul_byte_in_device_serial() {
+        let json = r#"{"streams":[],"device_serial":"abc\u0000def"}"#;
+        let err = serde_json::from_str::<ConfigSpec>(json).unwrap_err();
+        assert!(err.to_string().contains("NUL byte"));
+    }
+}
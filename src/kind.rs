@@ -0,0 +1,78 @@
+//! Stream kind and frame format enumerations.
+//!
+//! CAUTION: this checkout does not include the upstream `src/kind.rs`, and this sandbox
+//! has no network access to fetch it. The variants and `sys::rs2_*` mappings below were
+//! reconstructed from the public `rs2_stream`/`rs2_format` C enum names rather than
+//! copied from the real file. Each discriminant is taken by name from its `sys::rs2_*`
+//! constant, so a variant with no such constant fails to compile; a C enum value that
+//! has no variant here is not caught by the compiler and has no representation in these
+//! enums. Treat this as
+//! a stand-in to diff against the authoritative `src/kind.rs` before merging, not as a
+//! verified copy of it.
+
+use crate::common::*;
+use serde::{Deserialize, Serialize};
+
+/// The kind of data stream a sensor can produce.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamKind {
+    Any = sys::rs2_stream_RS2_STREAM_ANY as i32,
+    Depth = sys::rs2_stream_RS2_STREAM_DEPTH as i32,
+    Color = sys::rs2_stream_RS2_STREAM_COLOR as i32,
+    Infrared = sys::rs2_stream_RS2_STREAM_INFRARED as i32,
+    Fisheye = sys::rs2_stream_RS2_STREAM_FISHEYE as i32,
+    Gyro = sys::rs2_stream_RS2_STREAM_GYRO as i32,
+    Accel = sys::rs2_stream_RS2_STREAM_ACCEL as i32,
+    Gpio = sys::rs2_stream_RS2_STREAM_GPIO as i32,
+    Pose = sys::rs2_stream_RS2_STREAM_POSE as i32,
+    Confidence = sys::rs2_stream_RS2_STREAM_CONFIDENCE as i32,
+    Count = sys::rs2_stream_RS2_STREAM_COUNT as i32,
+}
+
+/// The in-memory layout of a frame's pixel or motion data.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Format {
+    #[serde(rename = "ANY")]
+    Any = sys::rs2_format_RS2_FORMAT_ANY as i32,
+    #[serde(rename = "Z16")]
+    Z16 = sys::rs2_format_RS2_FORMAT_Z16 as i32,
+    #[serde(rename = "DISPARITY16")]
+    Disparity16 = sys::rs2_format_RS2_FORMAT_DISPARITY16 as i32,
+    #[serde(rename = "XYZ32F")]
+    Xyz32f = sys::rs2_format_RS2_FORMAT_XYZ32F as i32,
+    #[serde(rename = "YUYV")]
+    Yuyv = sys::rs2_format_RS2_FORMAT_YUYV as i32,
+    #[serde(rename = "RGB8")]
+    Rgb8 = sys::rs2_format_RS2_FORMAT_RGB8 as i32,
+    #[serde(rename = "BGR8")]
+    Bgr8 = sys::rs2_format_RS2_FORMAT_BGR8 as i32,
+    #[serde(rename = "RGBA8")]
+    Rgba8 = sys::rs2_format_RS2_FORMAT_RGBA8 as i32,
+    #[serde(rename = "BGRA8")]
+    Bgra8 = sys::rs2_format_RS2_FORMAT_BGRA8 as i32,
+    #[serde(rename = "Y8")]
+    Y8 = sys::rs2_format_RS2_FORMAT_Y8 as i32,
+    #[serde(rename = "Y16")]
+    Y16 = sys::rs2_format_RS2_FORMAT_Y16 as i32,
+    #[serde(rename = "RAW10")]
+    Raw10 = sys::rs2_format_RS2_FORMAT_RAW10 as i32,
+    #[serde(rename = "RAW16")]
+    Raw16 = sys::rs2_format_RS2_FORMAT_RAW16 as i32,
+    #[serde(rename = "RAW8")]
+    Raw8 = sys::rs2_format_RS2_FORMAT_RAW8 as i32,
+    #[serde(rename = "UYVY")]
+    Uyvy = sys::rs2_format_RS2_FORMAT_UYVY as i32,
+    #[serde(rename = "MOTION_RAW")]
+    MotionRaw = sys::rs2_format_RS2_FORMAT_MOTION_RAW as i32,
+    #[serde(rename = "MOTION_XYZ32F")]
+    MotionXyz32f = sys::rs2_format_RS2_FORMAT_MOTION_XYZ32F as i32,
+    #[serde(rename = "GPIO_RAW")]
+    GpioRaw = sys::rs2_format_RS2_FORMAT_GPIO_RAW as i32,
+    #[serde(rename = "DISPARITY32")]
+    Disparity32 = sys::rs2_format_RS2_FORMAT_DISPARITY32 as i32,
+    #[serde(rename = "COUNT")]
+    Count = sys::rs2_format_RS2_FORMAT_COUNT as i32,
+}
@@ -10,6 +10,12 @@ use crate::{
     pipeline_kind::{self, PipelineState},
     pipeline_profile::PipelineProfile,
 };
+use futures::{channel::mpsc, SinkExt, Stream};
+use std::{
+    pin::Pin,
+    sync::{atomic::AtomicBool, Arc},
+    task::{Context as PollContext, Poll},
+};
 
 /// Represents the data pipeline from a RealSense device.
 #[derive(Debug)]
@@ -27,6 +33,17 @@ where
 pub type InactivePipeline = Pipeline<pipeline_kind::Inactive>;
 pub type ActivePipeline = Pipeline<pipeline_kind::Active>;
 
+impl<State> Pipeline<State>
+where
+    State: pipeline_kind::PipelineState,
+{
+    /// Gets the underlying pointer, for use by sibling modules such as [Config](crate::config::Config)
+    /// that need to pass it to FFI calls without taking ownership of the pipeline.
+    pub(crate) fn ptr(&self) -> *mut sys::rs2_pipeline {
+        self.ptr.as_ptr()
+    }
+}
+
 impl InactivePipeline {
     /// Creates an instance.
     pub fn new() -> Result<Self> {
@@ -243,6 +260,62 @@ impl ActivePipeline {
         Ok(frame)
     }
 
+    /// Turn the pipeline into a [Stream](futures::Stream) of frames.
+    ///
+    /// Unlike [wait_async](ActivePipeline::wait_async), which spawns a new thread and
+    /// channel for every call, this spawns a single long-lived worker thread that keeps
+    /// calling `rs2_pipeline_wait_for_frames` and forwards the results through a bounded
+    /// channel. The bound applies backpressure to the worker instead of letting frames
+    /// pile up in memory when the consumer falls behind.
+    ///
+    /// Dropping the returned stream signals the worker to exit and joins it before the
+    /// underlying pipeline is deleted. That only reclaims the resources, though — it does
+    /// not call `rs2_pipeline_stop`, the same as dropping an [ActivePipeline] directly.
+    /// To stop the pipeline and get an [InactivePipeline] back (e.g. to restart it with a
+    /// different config), call [FrameStream::stop] instead of dropping the stream.
+    /// Because the worker only notices the shutdown signal between FFI waits, both
+    /// dropping the stream and calling [FrameStream::stop] can block the calling thread
+    /// for up to [SHUTDOWN_POLL_INTERVAL](FrameStream::SHUTDOWN_POLL_INTERVAL) — don't do
+    /// either from an async executor thread that can't afford to stall.
+    pub fn into_frame_stream(self, buffer: usize) -> FrameStream {
+        let pipeline_ptr = AtomicPtr::new(self.ptr.as_ptr());
+        let running = Arc::new(AtomicBool::new(true));
+        let (mut tx, rx) = mpsc::channel(buffer);
+
+        let worker_running = running.clone();
+        let worker = thread::spawn(move || {
+            while worker_running.load(Ordering::Acquire) {
+                let mut checker = ErrorChecker::new();
+                let ptr = unsafe {
+                    sys::rs2_pipeline_wait_for_frames(
+                        pipeline_ptr.load(Ordering::Relaxed),
+                        FrameStream::SHUTDOWN_POLL_INTERVAL.as_millis() as c_uint,
+                        checker.inner_mut_ptr(),
+                    )
+                };
+
+                let result = match checker.check() {
+                    Err(RsError::Timeout(..)) => continue,
+                    Err(err) => Err(err),
+                    Ok(()) => Ok(unsafe { Frame::from_raw(ptr) }),
+                };
+
+                // Block until the bounded channel has room, so a slow consumer
+                // throttles the worker instead of frames piling up in memory.
+                if futures::executor::block_on(tx.send(result)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        FrameStream {
+            pipeline: Some(self),
+            rx,
+            running,
+            worker: Some(worker),
+        }
+    }
+
     /// Stop the pipeline.
     ///
     /// This method consumes the pipeline instance and returns pipeline markered inactive.
@@ -297,3 +370,61 @@ where
 }
 
 unsafe impl<State> Send for Pipeline<State> where State: pipeline_kind::PipelineState {}
+
+/// A [Stream](futures::Stream) of frames produced by [ActivePipeline::into_frame_stream].
+///
+/// The underlying pipeline keeps running for as long as the stream is alive, and is
+/// dropped together with it once the stream is dropped.
+pub struct FrameStream {
+    // `None` only while `stop` is in the middle of reclaiming it.
+    pipeline: Option<ActivePipeline>,
+    rx: mpsc::Receiver<Result<CompositeFrame>>,
+    running: Arc<AtomicBool>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl FrameStream {
+    /// Upper bound on how long the worker can take to notice that it should shut down,
+    /// and so on how long dropping a [FrameStream], or calling [FrameStream::stop], can
+    /// block the calling thread.
+    pub const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Gets the profile of the underlying pipeline.
+    pub fn profile(&self) -> &PipelineProfile {
+        self.pipeline.as_ref().unwrap().profile()
+    }
+
+    /// Signals the worker to exit and joins it, so `rs2_pipeline_stop` is valid to call.
+    fn shutdown_worker(&mut self) {
+        self.running.store(false, Ordering::Release);
+        self.rx.close();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    /// Stops the underlying pipeline, consuming the stream and returning the
+    /// [InactivePipeline] back to the caller.
+    ///
+    /// Unlike simply dropping the stream, which only deletes the pipeline, this joins the
+    /// worker thread and then calls [ActivePipeline::stop] on the reclaimed pipeline — the
+    /// only way to get back to an inactive pipeline once a frame stream has been created.
+    pub fn stop(mut self) -> Result<InactivePipeline> {
+        self.shutdown_worker();
+        self.pipeline.take().unwrap().stop()
+    }
+}
+
+impl Stream for FrameStream {
+    type Item = Result<CompositeFrame>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut PollContext<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.rx).poll_next(cx)
+    }
+}
+
+impl Drop for FrameStream {
+    fn drop(&mut self) {
+        self.shutdown_worker();
+    }
+}